@@ -0,0 +1,258 @@
+// Copyright 2016 Matthew D. Michelotti
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementations of `FloatChecker` that specify which floating point values
+//! are considered *valid*.
+//!
+//! In addition to the plain `NumChecker` (non-NaN) and `FiniteChecker`, this
+//! module defines a small lattice of checkers that further constrain the
+//! *sign* and whether *zero* is permitted.  Every checker in this module
+//! rejects NaN, as required by the `FloatChecker` contract.
+
+use num_traits::Float;
+use FloatChecker;
+
+/// A `FloatChecker` that considers all values valid except NaN.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct NumChecker;
+
+impl<F: Float> FloatChecker<F> for NumChecker {
+    #[inline]
+    fn check(value: F) -> bool {
+        !value.is_nan()
+    }
+
+    #[inline]
+    fn assert(value: F) {
+        debug_assert!(Self::check(value), "unexpected NaN");
+    }
+}
+
+/// A `FloatChecker` that considers all values valid except NaN, positive
+/// infinity, and negative infinity.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct FiniteChecker;
+
+impl<F: Float> FloatChecker<F> for FiniteChecker {
+    #[inline]
+    fn check(value: F) -> bool {
+        NumChecker::check(value) && value.is_finite()
+    }
+
+    #[inline]
+    fn assert(value: F) {
+        debug_assert!(Self::check(value), "unexpected NaN, +Inf, or -Inf");
+    }
+}
+
+/// A `FloatChecker` that accepts only values with a positive sign bit,
+/// i.e. `+0.0`, positive numbers, and positive infinity.
+///
+/// Note that `-0.0` is *rejected*, so that the positive/negative halves of the
+/// lattice do not overlap.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct PositiveChecker;
+
+impl<F: Float> FloatChecker<F> for PositiveChecker {
+    #[inline]
+    fn check(value: F) -> bool {
+        NumChecker::check(value) && value.is_sign_positive()
+    }
+
+    #[inline]
+    fn assert(value: F) {
+        debug_assert!(Self::check(value), "unexpected NaN or negative value");
+    }
+}
+
+/// A `FloatChecker` that accepts only values with a negative sign bit,
+/// i.e. `-0.0`, negative numbers, and negative infinity.
+///
+/// Note that `+0.0` is *rejected*, so that the positive/negative halves of the
+/// lattice do not overlap.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct NegativeChecker;
+
+impl<F: Float> FloatChecker<F> for NegativeChecker {
+    #[inline]
+    fn check(value: F) -> bool {
+        NumChecker::check(value) && value.is_sign_negative()
+    }
+
+    #[inline]
+    fn assert(value: F) {
+        debug_assert!(Self::check(value), "unexpected NaN or positive value");
+    }
+}
+
+/// A `FloatChecker` that accepts only values strictly greater than zero,
+/// i.e. positive numbers and positive infinity but neither signed zero.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct StrictlyPositiveChecker;
+
+impl<F: Float> FloatChecker<F> for StrictlyPositiveChecker {
+    #[inline]
+    fn check(value: F) -> bool {
+        NumChecker::check(value) && value > F::zero()
+    }
+
+    #[inline]
+    fn assert(value: F) {
+        debug_assert!(Self::check(value), "unexpected NaN or non-positive value");
+    }
+}
+
+/// A `FloatChecker` that accepts only values strictly less than zero,
+/// i.e. negative numbers and negative infinity but neither signed zero.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct StrictlyNegativeChecker;
+
+impl<F: Float> FloatChecker<F> for StrictlyNegativeChecker {
+    #[inline]
+    fn check(value: F) -> bool {
+        NumChecker::check(value) && value < F::zero()
+    }
+
+    #[inline]
+    fn assert(value: F) {
+        debug_assert!(Self::check(value), "unexpected NaN or non-negative value");
+    }
+}
+
+/// A `FloatChecker` that accepts any non-NaN value except `+0.0` and `-0.0`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct NonZeroChecker;
+
+impl<F: Float> FloatChecker<F> for NonZeroChecker {
+    #[inline]
+    fn check(value: F) -> bool {
+        NumChecker::check(value) && value != F::zero()
+    }
+
+    #[inline]
+    fn assert(value: F) {
+        debug_assert!(Self::check(value), "unexpected NaN or zero");
+    }
+}
+
+/// A `FloatChecker` that accepts only finite values with a positive sign bit
+/// (`+0.0` and finite positive numbers).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct PositiveFiniteChecker;
+
+impl<F: Float> FloatChecker<F> for PositiveFiniteChecker {
+    #[inline]
+    fn check(value: F) -> bool {
+        FiniteChecker::check(value) && value.is_sign_positive()
+    }
+
+    #[inline]
+    fn assert(value: F) {
+        debug_assert!(Self::check(value), "unexpected NaN, infinity, or negative value");
+    }
+}
+
+/// A `FloatChecker` that accepts only finite values with a negative sign bit
+/// (`-0.0` and finite negative numbers).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct NegativeFiniteChecker;
+
+impl<F: Float> FloatChecker<F> for NegativeFiniteChecker {
+    #[inline]
+    fn check(value: F) -> bool {
+        FiniteChecker::check(value) && value.is_sign_negative()
+    }
+
+    #[inline]
+    fn assert(value: F) {
+        debug_assert!(Self::check(value), "unexpected NaN, infinity, or positive value");
+    }
+}
+
+/// A `FloatChecker` that accepts only finite values strictly greater than zero.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct StrictlyPositiveFiniteChecker;
+
+impl<F: Float> FloatChecker<F> for StrictlyPositiveFiniteChecker {
+    #[inline]
+    fn check(value: F) -> bool {
+        FiniteChecker::check(value) && value > F::zero()
+    }
+
+    #[inline]
+    fn assert(value: F) {
+        debug_assert!(Self::check(value), "unexpected NaN, infinity, or non-positive value");
+    }
+}
+
+/// A `FloatChecker` that accepts only finite values strictly less than zero.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct StrictlyNegativeFiniteChecker;
+
+impl<F: Float> FloatChecker<F> for StrictlyNegativeFiniteChecker {
+    #[inline]
+    fn check(value: F) -> bool {
+        FiniteChecker::check(value) && value < F::zero()
+    }
+
+    #[inline]
+    fn assert(value: F) {
+        debug_assert!(Self::check(value), "unexpected NaN, infinity, or non-negative value");
+    }
+}
+
+/// A `FloatChecker` that accepts any finite value except `+0.0` and `-0.0`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct NonZeroFiniteChecker;
+
+impl<F: Float> FloatChecker<F> for NonZeroFiniteChecker {
+    #[inline]
+    fn check(value: F) -> bool {
+        FiniteChecker::check(value) && value != F::zero()
+    }
+
+    #[inline]
+    fn assert(value: F) {
+        debug_assert!(Self::check(value), "unexpected NaN, infinity, or zero");
+    }
+}
+
+/// A `FloatChecker` that rejects NaN and any value outside the inclusive range
+/// `[LO, HI]`.
+///
+/// Floating point values are not yet permitted as const-generic parameters, so
+/// the bounds are supplied as the raw IEEE-754 bit patterns of the `f64` values
+/// they represent (see `f64::to_bits`).  For example, `[0.0, 1.0]` is written
+/// `BoundedChecker<0x0000_0000_0000_0000, 0x3FF0_0000_0000_0000>`, for which the
+/// `UnitR64` alias is provided.
+///
+/// As with the other checkers, the range is only enforced via `debug_assert!`,
+/// so arithmetic that leaves the range trips the assert in debug builds while
+/// incurring no overhead in release builds.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct BoundedChecker<const LO: u64, const HI: u64>;
+
+impl<const LO: u64, const HI: u64> FloatChecker<f64> for BoundedChecker<LO, HI> {
+    #[inline]
+    fn check(value: f64) -> bool {
+        let lo = f64::from_bits(LO);
+        let hi = f64::from_bits(HI);
+        !value.is_nan() && value >= lo && value <= hi
+    }
+
+    #[inline]
+    fn assert(value: f64) {
+        debug_assert!(Self::check(value), "unexpected NaN or out-of-range value");
+    }
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
 //! This crate contains floating point types that panic if they are set
 //! to an illegal value, such as NaN.
 //!
@@ -34,6 +36,11 @@
 //! This crate makes use of the floating point and number traits in the
 //! popular `num_traits` crate.
 //!
+//! This crate supports `no_std`.  By default the `std` feature is enabled; to
+//! use `noisy_float` on bare metal, disable default features and enable the
+//! `libm` feature, which forwards the transcendental float methods (`sqrt`,
+//! `exp`, etc.) through `num_traits` to `libm`.
+//!
 //! #Examples
 //! An example using the `R64` type, which corresponds to *finite* `f64` values.
 //!
@@ -60,6 +67,8 @@
 //! assert!(values.iter().cloned().max() == Some(N32::infinity()));
 //! ```
 
+#[cfg(feature = "std")]
+extern crate core;
 extern crate num_traits;
 
 pub mod checkers;
@@ -79,8 +88,8 @@ pub mod prelude {
     pub use num_traits::cast::{ToPrimitive, NumCast};
 }
 
-use std::marker::PhantomData;
-use std::fmt;
+use core::marker::PhantomData;
+use core::fmt;
 use num_traits::Float;
 
 /// Trait for checking whether a floating point value is *valid*.
@@ -112,8 +121,15 @@ impl<F: Float, C: FloatChecker<F>> NoisyFloat<F, C> {
         Self::unchecked_new(value)
     }
     
+    /// Constructs a new `NoisyFloat` *without* checking `value`.
+    ///
+    /// This is a `const fn`, so it can be used to build compile-time constants
+    /// such as `const GRAVITY: R64 = R64::unchecked_new(9.81);` without any
+    /// runtime initialization.  Unlike `new`, it performs no `debug_assert!`,
+    /// because panicking `const fn`s are not yet available; it is therefore the
+    /// caller's responsibility to ensure that `value` satisfies `C`'s invariant.
     #[inline]
-    fn unchecked_new(value: F) -> NoisyFloat<F, C> {
+    pub const fn unchecked_new(value: F) -> NoisyFloat<F, C> {
         NoisyFloat {
             value: value,
             checker: PhantomData
@@ -136,6 +152,15 @@ impl<F: Float, C: FloatChecker<F>> NoisyFloat<F, C> {
     pub fn raw(self) -> F {
         self.value
     }
+
+    /// Returns the underlying float value, usable in a `const` context.
+    ///
+    /// This is the `const fn` companion to `raw`; it is useful for extracting
+    /// the raw value out of a `NoisyFloat` constant at compile time.
+    #[inline]
+    pub const fn const_raw(self) -> F {
+        self.value
+    }
 }
 
 /// Note: due to complications with Rust's type system, cannot implement `Into` generically like
@@ -156,6 +181,62 @@ impl<C: FloatChecker<f64>> Into<f64> for NoisyFloat<f64, C> {
     }
 }
 
+/// Note: the bit-level conversions are implemented per concrete float type,
+/// because the `to_bits`/`from_bits` methods are inherent to `f32`/`f64` rather
+/// than part of the `Float` trait.
+impl<C: FloatChecker<f32>> NoisyFloat<f32, C> {
+    /// Constructs a `NoisyFloat` from the raw IEEE-754 bit pattern of an `f32`,
+    /// returning `None` if the reconstructed value is rejected by the checker.
+    #[inline]
+    pub fn try_from_bits(bits: u32) -> Option<NoisyFloat<f32, C>> {
+        Self::try_new(f32::from_bits(bits))
+    }
+
+    /// Constructs a `NoisyFloat` from the raw IEEE-754 bit pattern of an `f32`.
+    ///
+    /// Like `new`, this runs the checker via `debug_assert!`, so that a bit
+    /// pattern which decodes to an invalid value (e.g. a NaN) cannot be
+    /// smuggled past the type's invariant.
+    #[inline]
+    pub fn from_bits(bits: u32) -> NoisyFloat<f32, C> {
+        Self::new(f32::from_bits(bits))
+    }
+
+    /// Returns the raw IEEE-754 bit pattern of the underlying `f32`.
+    #[inline]
+    pub fn to_bits(self) -> u32 {
+        self.value.to_bits()
+    }
+}
+
+/// Note: the bit-level conversions are implemented per concrete float type,
+/// because the `to_bits`/`from_bits` methods are inherent to `f32`/`f64` rather
+/// than part of the `Float` trait.
+impl<C: FloatChecker<f64>> NoisyFloat<f64, C> {
+    /// Constructs a `NoisyFloat` from the raw IEEE-754 bit pattern of an `f64`,
+    /// returning `None` if the reconstructed value is rejected by the checker.
+    #[inline]
+    pub fn try_from_bits(bits: u64) -> Option<NoisyFloat<f64, C>> {
+        Self::try_new(f64::from_bits(bits))
+    }
+
+    /// Constructs a `NoisyFloat` from the raw IEEE-754 bit pattern of an `f64`.
+    ///
+    /// Like `new`, this runs the checker via `debug_assert!`, so that a bit
+    /// pattern which decodes to an invalid value (e.g. a NaN) cannot be
+    /// smuggled past the type's invariant.
+    #[inline]
+    pub fn from_bits(bits: u64) -> NoisyFloat<f64, C> {
+        Self::new(f64::from_bits(bits))
+    }
+
+    /// Returns the raw IEEE-754 bit pattern of the underlying `f64`.
+    #[inline]
+    pub fn to_bits(self) -> u64 {
+        self.value.to_bits()
+    }
+}
+
 impl<F: Float + fmt::Debug, C: FloatChecker<F>> fmt::Debug for NoisyFloat<F, C> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
@@ -224,4 +305,59 @@ mod tests {
     fn r64_infinity() {
         r64(1.0) / r64(0.0);
     }
+
+    #[test]
+    fn signed_zero_lattice() {
+        use std::convert::TryFrom;
+
+        // Positive/negative types must reject the wrong signed zero, but accept
+        // the right one, so the lattice is sound.
+        assert!(Pos64::try_new(0.0).is_some());
+        assert!(Pos64::try_new(-0.0) == None);
+        assert!(Neg64::try_new(-0.0).is_some());
+        assert!(Neg64::try_new(0.0) == None);
+
+        // The strict variants reject zero of either sign.
+        assert!(StrictPos64::try_new(0.0) == None);
+        assert!(StrictPos64::try_new(-0.0) == None);
+        assert!(StrictPos64::try_new(1.0).is_some());
+        assert!(NonZeroR64::try_new(0.0) == None);
+        assert!(NonZeroR64::try_new(-0.0) == None);
+
+        // `From` edges are always-valid widenings: the source invariant is a
+        // subset of the target's.
+        let strict = StrictPosR64::try_new(2.5).unwrap();
+        let pos: Pos64 = Pos64::from(strict);
+        assert!(pos.raw() == 2.5);
+
+        // `TryFrom` edges may fail: an `R64` that is zero cannot become a
+        // `NonZeroR64`, but a non-zero one round-trips.
+        assert!(NonZeroR64::try_from(r64(0.0)) == Err(()));
+        assert!(NonZeroR64::try_from(r64(3.0)).unwrap().raw() == 3.0);
+
+        // `UnitR64` encodes the `[0.0, 1.0]` domain.
+        assert!(UnitR64::try_new(0.5).is_some());
+        assert!(UnitR64::try_new(1.0).is_some());
+        assert!(UnitR64::try_new(1.5) == None);
+        assert!(UnitR64::try_new(-0.5) == None);
+    }
+
+    #[test]
+    fn from_bits_rejects_nan() {
+        // A quiet-NaN bit pattern must not be smuggled past the invariant.
+        assert!(N64::try_from_bits(0x7ff8_0000_0000_0000) == None);
+        assert!(R64::try_from_bits(0x7ff8_0000_0000_0000) == None);
+        assert!(N32::try_from_bits(0x7fc0_0000) == None);
+        assert!(R32::try_from_bits(0x7fc0_0000) == None);
+
+        // A valid pattern round-trips through the checker.
+        let x = r64(1.5);
+        assert!(R64::try_from_bits(x.to_bits()) == Some(x));
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_bits_nan_panics() {
+        N64::from_bits(0x7ff8_0000_0000_0000);
+    }
 }
@@ -0,0 +1,228 @@
+// Copyright 2016 Matthew D. Michelotti
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Standard type definitions for `NoisyFloat`.
+//!
+//! Each of these types uses one of the `FloatChecker`s from the `checkers`
+//! module.  The naming convention follows `N` for "non-NaN" (the `NumChecker`)
+//! and `R` for "real", i.e. finite (the `FiniteChecker`); the sign- and
+//! zero-restricted checkers extend that convention with the `Pos`, `Neg`,
+//! `StrictPos`, `StrictNeg`, and `NonZero` prefixes.
+
+use core::convert::TryFrom;
+
+use NoisyFloat;
+use checkers::{NumChecker, FiniteChecker,
+               PositiveChecker, NegativeChecker,
+               StrictlyPositiveChecker, StrictlyNegativeChecker,
+               NonZeroChecker,
+               PositiveFiniteChecker, NegativeFiniteChecker,
+               StrictlyPositiveFiniteChecker, StrictlyNegativeFiniteChecker,
+               NonZeroFiniteChecker,
+               BoundedChecker};
+
+/// A 32-bit floating point number that may not be NaN.
+pub type N32 = NoisyFloat<f32, NumChecker>;
+
+/// A 64-bit floating point number that may not be NaN.
+pub type N64 = NoisyFloat<f64, NumChecker>;
+
+/// A 32-bit floating point number that must be finite (i.e. not NaN, not +Inf,
+/// and not -Inf).
+pub type R32 = NoisyFloat<f32, FiniteChecker>;
+
+/// A 64-bit floating point number that must be finite (i.e. not NaN, not +Inf,
+/// and not -Inf).
+pub type R64 = NoisyFloat<f64, FiniteChecker>;
+
+/// A non-NaN 32-bit floating point number with a positive sign bit.
+pub type Pos32 = NoisyFloat<f32, PositiveChecker>;
+
+/// A non-NaN 64-bit floating point number with a positive sign bit.
+pub type Pos64 = NoisyFloat<f64, PositiveChecker>;
+
+/// A non-NaN 32-bit floating point number with a negative sign bit.
+pub type Neg32 = NoisyFloat<f32, NegativeChecker>;
+
+/// A non-NaN 64-bit floating point number with a negative sign bit.
+pub type Neg64 = NoisyFloat<f64, NegativeChecker>;
+
+/// A non-NaN 32-bit floating point number strictly greater than zero.
+pub type StrictPos32 = NoisyFloat<f32, StrictlyPositiveChecker>;
+
+/// A non-NaN 64-bit floating point number strictly greater than zero.
+pub type StrictPos64 = NoisyFloat<f64, StrictlyPositiveChecker>;
+
+/// A non-NaN 32-bit floating point number strictly less than zero.
+pub type StrictNeg32 = NoisyFloat<f32, StrictlyNegativeChecker>;
+
+/// A non-NaN 64-bit floating point number strictly less than zero.
+pub type StrictNeg64 = NoisyFloat<f64, StrictlyNegativeChecker>;
+
+/// A non-NaN 32-bit floating point number that is not zero.
+pub type NonZeroN32 = NoisyFloat<f32, NonZeroChecker>;
+
+/// A non-NaN 64-bit floating point number that is not zero.
+pub type NonZeroN64 = NoisyFloat<f64, NonZeroChecker>;
+
+/// A finite 32-bit floating point number with a positive sign bit.
+pub type PosR32 = NoisyFloat<f32, PositiveFiniteChecker>;
+
+/// A finite 64-bit floating point number with a positive sign bit.
+pub type PosR64 = NoisyFloat<f64, PositiveFiniteChecker>;
+
+/// A finite 32-bit floating point number with a negative sign bit.
+pub type NegR32 = NoisyFloat<f32, NegativeFiniteChecker>;
+
+/// A finite 64-bit floating point number with a negative sign bit.
+pub type NegR64 = NoisyFloat<f64, NegativeFiniteChecker>;
+
+/// A finite 32-bit floating point number strictly greater than zero.
+pub type StrictPosR32 = NoisyFloat<f32, StrictlyPositiveFiniteChecker>;
+
+/// A finite 64-bit floating point number strictly greater than zero.
+pub type StrictPosR64 = NoisyFloat<f64, StrictlyPositiveFiniteChecker>;
+
+/// A finite 32-bit floating point number strictly less than zero.
+pub type StrictNegR32 = NoisyFloat<f32, StrictlyNegativeFiniteChecker>;
+
+/// A finite 64-bit floating point number strictly less than zero.
+pub type StrictNegR64 = NoisyFloat<f64, StrictlyNegativeFiniteChecker>;
+
+/// A finite 32-bit floating point number that is not zero.
+pub type NonZeroR32 = NoisyFloat<f32, NonZeroFiniteChecker>;
+
+/// A finite 64-bit floating point number that is not zero.
+pub type NonZeroR64 = NoisyFloat<f64, NonZeroFiniteChecker>;
+
+/// A 64-bit floating point number restricted to the inclusive range
+/// `[0.0, 1.0]`, as used for probabilities and interpolation factors.
+///
+/// The bounds are the IEEE-754 bit patterns of `0.0` and `1.0`, respectively.
+pub type UnitR64 = NoisyFloat<f64, BoundedChecker<0x0000_0000_0000_0000, 0x3FF0_0000_0000_0000>>;
+
+/// Shorthand for `N32::new(value)`.
+#[inline]
+pub fn n32(value: f32) -> N32 {
+    N32::new(value)
+}
+
+/// Shorthand for `N64::new(value)`.
+#[inline]
+pub fn n64(value: f64) -> N64 {
+    N64::new(value)
+}
+
+/// Shorthand for `R32::new(value)`.
+#[inline]
+pub fn r32(value: f32) -> R32 {
+    R32::new(value)
+}
+
+/// Shorthand for `R64::new(value)`.
+#[inline]
+pub fn r64(value: f64) -> R64 {
+    R64::new(value)
+}
+
+// Conversions between `NoisyFloat` instantiations.
+//
+// A `From` conversion is provided whenever the set of values accepted by the
+// source checker is a subset of those accepted by the destination checker, so
+// that the move is always valid.  A `TryFrom` conversion is provided for the
+// interesting narrowings, where the source value may fall outside the
+// destination's invariant and is therefore validated through the checker.
+
+/// Generates infallible `From` conversions that simply re-tag the value with a
+/// wider checker, for both `f32` and `f64`.
+macro_rules! noisy_from {
+    ($($src:ident => $dst:ident,)*) => {$(
+        impl From<NoisyFloat<f32, ::checkers::$src>> for NoisyFloat<f32, ::checkers::$dst> {
+            #[inline]
+            fn from(src: NoisyFloat<f32, ::checkers::$src>) -> Self {
+                NoisyFloat::unchecked_new(src.raw())
+            }
+        }
+
+        impl From<NoisyFloat<f64, ::checkers::$src>> for NoisyFloat<f64, ::checkers::$dst> {
+            #[inline]
+            fn from(src: NoisyFloat<f64, ::checkers::$src>) -> Self {
+                NoisyFloat::unchecked_new(src.raw())
+            }
+        }
+    )*};
+}
+
+/// Generates fallible `TryFrom` conversions that re-validate the value through
+/// the destination checker, for both `f32` and `f64`.
+macro_rules! noisy_try_from {
+    ($($src:ident => $dst:ident,)*) => {$(
+        impl TryFrom<NoisyFloat<f32, ::checkers::$src>> for NoisyFloat<f32, ::checkers::$dst> {
+            type Error = ();
+            #[inline]
+            fn try_from(src: NoisyFloat<f32, ::checkers::$src>) -> Result<Self, ()> {
+                NoisyFloat::try_new(src.raw()).ok_or(())
+            }
+        }
+
+        impl TryFrom<NoisyFloat<f64, ::checkers::$src>> for NoisyFloat<f64, ::checkers::$dst> {
+            type Error = ();
+            #[inline]
+            fn try_from(src: NoisyFloat<f64, ::checkers::$src>) -> Result<Self, ()> {
+                NoisyFloat::try_new(src.raw()).ok_or(())
+            }
+        }
+    )*};
+}
+
+noisy_from! {
+    FiniteChecker => NumChecker,
+    PositiveChecker => NumChecker,
+    NegativeChecker => NumChecker,
+    NonZeroChecker => NumChecker,
+    StrictlyPositiveChecker => PositiveChecker,
+    StrictlyPositiveChecker => NonZeroChecker,
+    StrictlyNegativeChecker => NegativeChecker,
+    StrictlyNegativeChecker => NonZeroChecker,
+    PositiveFiniteChecker => PositiveChecker,
+    PositiveFiniteChecker => FiniteChecker,
+    NegativeFiniteChecker => NegativeChecker,
+    NegativeFiniteChecker => FiniteChecker,
+    NonZeroFiniteChecker => NonZeroChecker,
+    NonZeroFiniteChecker => FiniteChecker,
+    StrictlyPositiveFiniteChecker => StrictlyPositiveChecker,
+    StrictlyPositiveFiniteChecker => PositiveChecker,
+    StrictlyPositiveFiniteChecker => PositiveFiniteChecker,
+    StrictlyPositiveFiniteChecker => NonZeroFiniteChecker,
+    StrictlyNegativeFiniteChecker => StrictlyNegativeChecker,
+    StrictlyNegativeFiniteChecker => NegativeChecker,
+    StrictlyNegativeFiniteChecker => NegativeFiniteChecker,
+    StrictlyNegativeFiniteChecker => NonZeroFiniteChecker,
+}
+
+noisy_try_from! {
+    NumChecker => FiniteChecker,
+    NumChecker => PositiveChecker,
+    NumChecker => NegativeChecker,
+    NumChecker => NonZeroChecker,
+    FiniteChecker => PositiveFiniteChecker,
+    FiniteChecker => NegativeFiniteChecker,
+    FiniteChecker => StrictlyPositiveFiniteChecker,
+    FiniteChecker => StrictlyNegativeFiniteChecker,
+    FiniteChecker => NonZeroFiniteChecker,
+    PositiveChecker => StrictlyPositiveChecker,
+    PositiveChecker => PositiveFiniteChecker,
+    NegativeChecker => StrictlyNegativeChecker,
+    NonZeroChecker => NonZeroFiniteChecker,
+}